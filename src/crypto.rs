@@ -50,6 +50,19 @@ impl Crypto {
         }
     }
 
+    /// Computes the natural log of `n!`, using a Stirling series approximation so
+    /// the cost stays constant regardless of `n`.
+    pub fn ln_factorial(n: u32) -> f32 {
+        if n <= 1 {
+            return 0.;
+        }
+
+        let n = n as f64;
+
+        (n * n.ln() - n + 0.5 * (2. * std::f64::consts::PI * n).ln() + 1. / (12. * n)
+            - 1. / (360. * n.powi(3))) as f32
+    }
+
     /// Linearly interpolates between `a` and `b` by `t`.
     ///
     /// `t` is clamped to the range `[0..1]`.
@@ -0,0 +1,44 @@
+use crate::random::Random;
+
+/// Draws a uniform value in `(0, 1)` with the full binary precision an `f64` can
+/// represent, including subnormal-scale spacing near `0`.
+///
+/// Combines as many `u32` draws as needed: each all-zero draw extends the exponent
+/// by another 32 bits (a geometrically distributed downward shift), and the number
+/// of leading zero bits in the first non-zero draw extends it further, so smaller
+/// values are reached with proportionally smaller probability, just like real binary
+/// subdivision. Based on Taylor R. Campbell's `random_real` algorithm.
+pub(crate) fn uniform_open01(random: &mut Random) -> f64 {
+    let mut exponent: i32 = 0;
+    let mut word = random.next_u32();
+
+    while word == 0 {
+        exponent -= 32;
+        word = random.next_u32();
+    }
+
+    let shift = word.leading_zeros();
+    exponent -= shift as i32;
+
+    let mut significand = ((word as u64) << 32) | random.next_u32() as u64;
+    significand <<= shift;
+    significand |= 1; // Round to odd, so truncating to `f32` later is unbiased.
+
+    // A significand close to 2^64 rounds up to 2^64 in `f64`; keep the result below `1`.
+    ((significand as f64) * 2f64.powi(exponent - 64)).min(1. - f64::EPSILON / 2.)
+}
+
+/// Returns the largest representable `f32` strictly less than `x`.
+pub(crate) fn next_down(x: f32) -> f32 {
+    if x.is_nan() || x == f32::NEG_INFINITY {
+        return x;
+    }
+
+    if x == 0. {
+        return -f32::from_bits(1);
+    }
+
+    let bits = x.to_bits();
+
+    f32::from_bits(if x > 0. { bits - 1 } else { bits + 1 })
+}
@@ -9,5 +9,9 @@
 //!
 //! This project is not affiliated with Unity Technologies.
 mod crypto;
+mod high_precision;
 mod random;
+mod weighted_index;
+mod ziggurat;
 pub use crate::random::{Random, State};
+pub use crate::weighted_index::{WeightedIndex, WeightedIndexError};
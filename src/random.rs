@@ -1,9 +1,14 @@
+use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 #[cfg(feature = "serde")]
 use serde_crate::{Deserialize, Serialize};
 
+#[cfg(feature = "rand_core")]
+use rand_core_crate::{Error, RngCore, SeedableRng};
+
 use crate::crypto::Crypto;
+use crate::weighted_index::WeightedIndex;
 
 /// The internal state of the random number generator.
 #[cfg_attr(
@@ -274,16 +279,172 @@ impl Random {
         (r, g, b, a)
     }
 
-    /// Generates the next u32.
-    fn next_u32(&mut self) -> u32 {
+    /// Returns a random `f32` sampled from a normal (Gaussian) distribution
+    /// with the given `mean` and `std_dev`, using the Ziggurat algorithm.
+    ///
+    /// Unity itself has no equivalent of this, but it is commonly needed
+    /// for procedural distributions.
+    pub fn next_gaussian(&mut self, mean: f32, std_dev: f32) -> f32 {
+        let standard_normal = crate::ziggurat::standard_normal(self) as f32;
+
+        Crypto::precision_f32(standard_normal * std_dev + mean, 7)
+    }
+
+    /// Returns a random index into `weights`, chosen with probability proportional to
+    /// its value, using Vose's alias method.
+    ///
+    /// For repeated draws from the same weights, build a [`WeightedIndex`] once and
+    /// call [`WeightedIndex::sample`] instead, to avoid rebuilding it on every draw.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` is empty, contains a negative value, or sums to `0`.
+    pub fn weighted_index(&mut self, weights: &[f32]) -> usize {
+        WeightedIndex::new(weights)
+            .expect("weights must be non-negative and not all zero")
+            .sample(self)
+    }
+
+    /// Shuffles `slice` in place, using the Durstenfeld variant of the Fisher–Yates shuffle.
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.range_int(0, i as i32 + 1) as usize;
+
+            slice.swap(i, j);
+        }
+    }
+
+    /// Returns `amount` elements chosen from `slice` without replacement.
+    ///
+    /// Uses a partial Fisher–Yates shuffle over a sparse virtual copy of `slice`,
+    /// so only `amount` elements are ever touched, regardless of `slice`'s length.
+    pub fn choose_multiple<'a, T>(&mut self, slice: &'a [T], amount: usize) -> Vec<&'a T> {
+        let len = slice.len();
+        let amount = amount.min(len);
+
+        let mut swapped = HashMap::new();
+        let mut chosen = Vec::with_capacity(amount);
+
+        for i in 0..amount {
+            let j = self.range_int(i as i32, len as i32) as usize;
+
+            let value_at_i = *swapped.get(&i).unwrap_or(&i);
+            let value_at_j = *swapped.get(&j).unwrap_or(&j);
+
+            swapped.insert(i, value_at_j);
+            swapped.insert(j, value_at_i);
+
+            chosen.push(&slice[value_at_j]);
+        }
+
+        chosen
+    }
+
+    /// Returns a random `f32` sampled from an exponential distribution with the given `lambda`.
+    pub fn exponential(&mut self, lambda: f32) -> f32 {
+        let mut u = self.next_f32();
+
+        while u >= 1. {
+            u = self.next_f32();
+        }
+
+        Crypto::precision_f32(-(1. - u).ln() / lambda, 7)
+    }
+
+    /// Returns a random `u32` sampled from a Poisson distribution with the given `lambda`.
+    ///
+    /// Uses Knuth's method for small `lambda`, and Hörmann's transformed rejection
+    /// method (PTRS) for `lambda > 30`, to keep the expected number of draws bounded.
+    pub fn poisson(&mut self, lambda: f32) -> u32 {
+        if lambda > 30. {
+            self.poisson_ptrs(lambda)
+        } else {
+            self.poisson_knuth(lambda)
+        }
+    }
+
+    /// Returns a random `f32` within `[low..high)` using the full precision the `f32`
+    /// type can represent, including subnormal-scale spacing near `low`.
+    ///
+    /// Unlike [`range_float`](Random::range_float), this does not match Unity's bit
+    /// stream; it trades that bit-compatibility for statistical quality when sampling
+    /// very wide or very narrow ranges.
+    pub fn range_float_high_precision(&mut self, low: f32, high: f32) -> f32 {
+        let u = crate::high_precision::uniform_open01(self);
+        let value = low as f64 + u * (high as f64 - low as f64);
+        let mut rounded = value as f32;
+
+        if rounded as f64 > value {
+            rounded = crate::high_precision::next_down(rounded);
+        }
+
+        if rounded >= high {
+            crate::high_precision::next_down(high)
+        } else {
+            rounded
+        }
+    }
+
+    /// Generates the next raw `u32` from the underlying stream.
+    pub fn next_u32(&mut self) -> u32 {
         Crypto::next_u32(&mut self.state)
     }
 
-    /// Generates the next f32.
-    fn next_f32(&mut self) -> f32 {
+    /// Generates the next raw `f32`, without any rounding applied.
+    pub fn next_f32(&mut self) -> f32 {
         Crypto::next_f32(&mut self.state)
     }
 
+    /// Draws from a Poisson distribution via Knuth's method.
+    fn poisson_knuth(&mut self, lambda: f32) -> u32 {
+        let limit = (-lambda).exp();
+        let mut k = 0;
+        let mut p = 1.;
+
+        loop {
+            k += 1;
+            p *= self.next_f32();
+
+            if p <= limit {
+                break;
+            }
+        }
+
+        k - 1
+    }
+
+    /// Draws from a Poisson distribution via Hörmann's transformed rejection method (PTRS).
+    fn poisson_ptrs(&mut self, lambda: f32) -> u32 {
+        let s = lambda.sqrt();
+        let log_lambda = lambda.ln();
+        let b = 0.931 + 2.53 * s;
+        let a = -0.059 + 0.02483 * b;
+        let inv_alpha = 1.1239 + 1.1328 / (b - 3.4);
+        let v_r = 0.9277 - 3.6224 / (b - 2.);
+
+        loop {
+            let u = self.next_f32() - 0.5;
+            let v = self.next_f32();
+            let us = 0.5 - u.abs();
+            let k = ((2. * a / us + b) * u + lambda + 0.43).floor();
+
+            if us >= 0.07 && v <= v_r {
+                return k as u32;
+            }
+
+            if k < 0. || (us < 0.013 && v > us) {
+                continue;
+            }
+
+            let lhs = v.ln() + inv_alpha.ln() - (a / (us * us) + b).ln();
+            let rhs = -lambda + k * log_lambda - Crypto::ln_factorial(k as u32);
+
+            if lhs <= rhs {
+                return k as u32;
+            }
+        }
+    }
+
     /// Generates the next f32, converting it to the range `[min..max]` (inclusive).
     fn range_float_intern(&mut self, min: f32, max: f32) -> f32 {
         let next = self.next_f32();
@@ -312,3 +473,58 @@ impl Random {
             .as_secs()
     }
 }
+
+/// Plugs `Random` into the `rand` ecosystem, so it can be used with
+/// `Uniform`, `Bernoulli`, sampling and shuffling helpers from the `rand` crate.
+#[cfg(feature = "rand_core")]
+impl RngCore for Random {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let low = self.next_u32() as u64;
+        let high = self.next_u32() as u64;
+
+        (high << 32) | low
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(4);
+
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u32().to_le_bytes());
+        }
+
+        let remainder = chunks.into_remainder();
+
+        if !remainder.is_empty() {
+            let bytes = self.next_u32().to_le_bytes();
+            remainder.copy_from_slice(&bytes[..remainder.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Allows seeding `Random` the way `rand`'s ecosystem expects,
+/// while preserving Unity-equivalent seeding via `Crypto::init_state`.
+#[cfg(feature = "rand_core")]
+impl SeedableRng for Random {
+    type Seed = [u8; 4];
+
+    fn from_seed(seed: Self::Seed) -> Random {
+        Random {
+            state: Crypto::init_state(u32::from_le_bytes(seed)),
+        }
+    }
+
+    fn seed_from_u64(seed: u64) -> Random {
+        Random {
+            state: Crypto::init_state(seed as u32),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::random::Random;
+
+/// The error returned when constructing a [`WeightedIndex`] from invalid weights.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WeightedIndexError {
+    /// A weight was negative.
+    NegativeWeight,
+    /// All weights were zero.
+    AllZero,
+}
+
+impl std::fmt::Display for WeightedIndexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            WeightedIndexError::NegativeWeight => write!(f, "weights must not be negative"),
+            WeightedIndexError::AllZero => write!(f, "weights must not all be zero"),
+        }
+    }
+}
+
+impl std::error::Error for WeightedIndexError {}
+
+/// A reusable weighted index sampler, built with Vose's alias method.
+///
+/// Building a [`WeightedIndex`] is `O(n)`, after which each [`sample`](WeightedIndex::sample)
+/// call is `O(1)`, making it worthwhile to keep around for repeated draws from the same
+/// set of weights (e.g. a loot table).
+pub struct WeightedIndex {
+    prob: Vec<f32>,
+    alias: Vec<usize>,
+}
+
+impl WeightedIndex {
+    /// Builds a new [`WeightedIndex`] from `weights`.
+    ///
+    /// Weights are normalized internally, so they don't need to sum to `1`.
+    pub fn new(weights: &[f32]) -> Result<WeightedIndex, WeightedIndexError> {
+        if weights.iter().any(|&weight| weight < 0.) {
+            return Err(WeightedIndexError::NegativeWeight);
+        }
+
+        let sum: f32 = weights.iter().sum();
+
+        if sum <= 0. {
+            return Err(WeightedIndexError::AllZero);
+        }
+
+        let n = weights.len();
+        let mut scaled: Vec<f32> = weights.iter().map(|&weight| weight * n as f32 / sum).collect();
+
+        let mut prob = vec![0.; n];
+        let mut alias = vec![0; n];
+
+        let mut small: Vec<usize> = Vec::new();
+        let mut large: Vec<usize> = Vec::new();
+
+        for (i, &weight) in scaled.iter().enumerate() {
+            if weight < 1. {
+                small.push(i);
+            } else {
+                large.push(i);
+            }
+        }
+
+        while !small.is_empty() && !large.is_empty() {
+            let s = small.pop().unwrap();
+            let l = large.pop().unwrap();
+
+            prob[s] = scaled[s];
+            alias[s] = l;
+
+            scaled[l] = (scaled[l] + scaled[s]) - 1.;
+
+            if scaled[l] < 1. {
+                small.push(l);
+            } else {
+                large.push(l);
+            }
+        }
+
+        for i in large.into_iter().chain(small) {
+            prob[i] = 1.;
+            alias[i] = i;
+        }
+
+        Ok(WeightedIndex { prob, alias })
+    }
+
+    /// Draws a weighted index using the given `random` stream.
+    pub fn sample(&self, random: &mut Random) -> usize {
+        let i = random.range_int(0, self.prob.len() as i32) as usize;
+
+        if random.next_f32() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
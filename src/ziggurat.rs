@@ -0,0 +1,575 @@
+use crate::random::Random;
+
+/// Layer boundaries `x[0..=256]` for the Ziggurat method (Marsaglia & Tsang), with 256 layers
+/// over the standard normal distribution. `x[0]` is the tail start (approx. `3.6553`), `x[256]` is `0.0`.
+#[allow(clippy::excessive_precision)]
+const X: [f64; 257] = [
+    3.655301241000456,
+    3.4505006677853434,
+    3.3215208650411627,
+    3.2258946966390054,
+    3.149246204601255,
+    3.0849160841193584,
+    3.0292577056267103,
+    2.980050812345228,
+    2.9358401695205125,
+    2.895618627723956,
+    2.858659337260854,
+    2.824419992489949,
+    2.7924848691313393,
+    2.7625280320132446,
+    2.7342890483378155,
+    2.707556420243092,
+    2.682155962293165,
+    2.657942448722683,
+    2.6347934829105135,
+    2.612604913823274,
+    2.591287352385733,
+    2.5707634847663217,
+    2.550965972836865,
+    2.5318357938692726,
+    2.5133209133385397,
+    2.49537521351339,
+    2.4779576207113636,
+    2.461031388471252,
+    2.4445635042751954,
+    2.428524195044667,
+    2.4128865122546514,
+    2.397625981717201,
+    2.382720306267185,
+    2.3681491120125515,
+    2.353893730683251,
+    2.339937012067291,
+    2.32626316166125,
+    2.312857599560965,
+    2.299706837331803,
+    2.2867983701685612,
+    2.2741205821141586,
+    2.261662662477847,
+    2.249414531896022,
+    2.2373667767260637,
+    2.2255105906670227,
+    2.2138377226689356,
+    2.2023404303319936,
+    2.1910114381129504,
+    2.179843899753412,
+    2.1688313644263317,
+    2.157967746165933,
+    2.1472472962046028,
+    2.136664577889817,
+    2.1262144438963686,
+    2.115892015485256,
+    2.1056926635915127,
+    2.095611991549883,
+    2.085645819290186,
+    2.0757901688540605,
+    2.0660412511019945,
+    2.0563954534944933,
+    2.04684932884429,
+    2.0373995849478757,
+    2.028043075014603,
+    2.0187767888203636,
+    2.009597844520529,
+    2.0005034810636215,
+    1.9914910511531665,
+    1.982558014710469,
+    1.97370193279575,
+    1.9649204619492364,
+    1.9562113489175146,
+    1.9475724257337435,
+    1.9390016051232795,
+    1.9304968762088939,
+    1.922056300492122,
+    1.9136780080893943,
+    1.905360194203499,
+    1.897101115812638,
+    1.8888990885608623,
+    1.8807524838350749,
+    1.8726597260150248,
+    1.8646192898838636,
+    1.8566296981878445,
+    1.8486895193346862,
+    1.8407973652209555,
+    1.8329518891795968,
+    1.825151784039427,
+    1.8173957802890481,
+    1.8096826443382168,
+    1.8020111768702214,
+    1.7943802112793141,
+    1.7867886121876784,
+    1.7792352740368123,
+    1.771719119748586,
+    1.764239099451563,
+    1.7567941892684855,
+    1.749383390161113,
+    1.7420057268288642,
+    1.734660246657948,
+    1.7273460187179006,
+    1.720062132802642,
+    1.7128076985133542,
+    1.7055818443806654,
+    1.698383717023771,
+    1.6912124803442816,
+    1.6840673147527223,
+    1.6769474164257254,
+    1.669851996592091,
+    1.6627802808459813,
+    1.6557315084856272,
+    1.6487049318760092,
+    1.641699815834067,
+    1.6347154370350645,
+    1.627751083438817,
+    1.6208060537345474,
+    1.6138796568032097,
+    1.6069712111961652,
+    1.6000800446291603,
+    1.593205493490597,
+    1.5863469023631365,
+    1.5795036235577142,
+    1.5726750166590877,
+    1.5658604480820684,
+    1.5590592906376253,
+    1.5522709231080742,
+    1.545494729830594,
+    1.538730100288337,
+    1.5319764287084132,
+    1.5252331136660668,
+    1.518499557694351,
+    1.5117751668986508,
+    1.5050593505753955,
+    1.4983515208343232,
+    1.491651092223661,
+    1.4849574813575999,
+    1.4782701065454353,
+    1.4715883874217577,
+    1.4649117445770716,
+    1.4582395991882187,
+    1.4515713726479835,
+    1.4449064861932457,
+    1.438244360531041,
+    1.4315844154618804,
+    1.4249260694996648,
+    1.4182687394875193,
+    1.4116118402088504,
+    1.4049547839929155,
+    1.3982969803141658,
+    1.3916378353846037,
+    1.3849767517383669,
+    1.378313127807715,
+    1.371646357489572,
+    1.3649758297017272,
+    1.3583009279277638,
+    1.351621029749739,
+    1.3449355063675847,
+    1.3382437221041474,
+    1.331545033894729,
+    1.3248387907599162,
+    1.3181243332604284,
+    1.311400992932626,
+    1.304668091703245,
+    1.2979249412818255,
+    1.2911708425292128,
+    1.284405084800388,
+    1.27762694525978,
+    1.2708356881670728,
+    1.2640305641313883,
+    1.2572108093315661,
+    1.2503756447001004,
+    1.2435242750681086,
+    1.2366558882685037,
+    1.229769654194334,
+    1.222864723809002,
+    1.2159402281048248,
+    1.2089952770061003,
+    1.2020289582125367,
+    1.1950403359785537,
+    1.1880284498235765,
+    1.180992313168037,
+    1.17393091188932,
+    1.1668432027913889,
+    1.1597281119812637,
+    1.1525845331448872,
+    1.1454113257142393,
+    1.1382073129167778,
+    1.1309712796974378,
+    1.123701970502474,
+    1.1163980869133667,
+    1.1090582851178383,
+    1.1016811732037062,
+    1.094265308259821,
+    1.0868091932666888,
+    1.0793112737575072,
+    1.07176993422827,
+    1.0641834942732196,
+    1.0565502044192785,
+    1.0488682416300728,
+    1.0411357044467466,
+    1.0333506077288845,
+    1.0255108769544448,
+    1.0176143420325627,
+    1.0096587305773161,
+    1.0016416605839387,
+    0.9935606324413604,
+    0.9854130202062112,
+    0.9771960620532963,
+    0.9689068498058419,
+    0.9605423174351866,
+    0.9520992284037323,
+    0.9435741617064108,
+    0.9349634964441731,
+    0.9262633947374028,
+    0.9174697827569255,
+    0.9085783296144473,
+    0.899584423811621,
+    0.8904831468959992,
+    0.881269243911024,
+    0.871937090153564,
+    0.862480653663358,
+    0.8528934527602723,
+    0.8431685078126478,
+    0.8332982862569637,
+    0.8232746396874103,
+    0.813088731583146,
+    0.8027309539269688,
+    0.7921908305732802,
+    0.781456904720612,
+    0.7705166072009292,
+    0.7593561014683893,
+    0.7479601000907578,
+    0.7363116461286794,
+    0.7243918509064665,
+    0.7121795771542019,
+    0.6996510530755193,
+    0.6867793981869024,
+    0.6735340352119517,
+    0.6598799530288236,
+    0.6457767723119103,
+    0.6311775459408008,
+    0.6160271969985096,
+    0.6002604524624666,
+    0.5837990605855414,
+    0.5665479668933556,
+    0.5483899353730228,
+    0.5291777758242739,
+    0.5087227506969793,
+    0.4867766190128151,
+    0.46300252420194554,
+    0.43692504348694344,
+    0.40783806478395274,
+    0.37461784418310917,
+    0.33528946468875165,
+    0.2857950854281927,
+    0.21495853889896804,
+    0.0
+];
+
+/// Layer densities `y[0..256)`, i.e. `y[i] == exp(-0.5 * x[i] * x[i])`.
+#[allow(clippy::excessive_precision)]
+const Y: [f64; 256] = [
+    0.001255007687110201,
+    0.002598093351818514,
+    0.004020896350471239,
+    0.0054989489945624515,
+    0.007020815998495718,
+    0.008579723234711576,
+    0.010171138548162373,
+    0.011791793894803651,
+    0.01343920966256187,
+    0.015111433766566835,
+    0.016806885871334217,
+    0.01852425828888235,
+    0.02026244974413055,
+    0.02202051932267956,
+    0.023797653397007998,
+    0.02559314122224822,
+    0.02740635651123468,
+    0.02923674324712786,
+    0.031083804570572804,
+    0.03294709394365684,
+    0.03482620803052191,
+    0.03672078089310246,
+    0.0386304792088246,
+    0.04055499829267526,
+    0.042494058759734804,
+    0.04444740370304206,
+    0.04641479629009353,
+    0.048396017702414876,
+    0.05039086535855462,
+    0.05239915137296677,
+    0.0544207012125754,
+    0.056455352520063035,
+    0.05850295407861058,
+    0.06056336489731441,
+    0.06263645340009286,
+    0.06472209670377471,
+    0.06682017997339268,
+    0.06893059584460146,
+    0.07105324390469393,
+    0.07318803022496867,
+    0.07533486693826244,
+    0.07749367185634386,
+    0.07966436812260293,
+    0.08184688389609182,
+    0.08404115206349624,
+    0.08624710997606083,
+    0.08846469920887037,
+    0.090693865340211,
+    0.09293455774901307,
+    0.09518672942861527,
+    0.09745033681529504,
+    0.09972533963018916,
+    0.102011700733382,
+    0.10430938598907467,
+    0.10661836414086523,
+    0.10893860669627385,
+    0.1112700878197369,
+    0.1136127842333737,
+    0.11596667512490055,
+    0.11833174206212786,
+    0.12070796891353251,
+    0.1230953417744458,
+    0.12549384889844117,
+    0.12790348063354523,
+    0.13032422936292953,
+    0.13275608944977296,
+    0.13519905718601155,
+    0.13765313074471788,
+    0.1401183101358758,
+    0.14259459716533515,
+    0.145081995396751,
+    0.14758051011632803,
+    0.1500901483002057,
+    0.15261091858433445,
+    0.15514283123670491,
+    0.15768589813180356,
+    0.16024013272718,
+    0.16280555004201852,
+    0.16538216663761737,
+    0.167970000599686,
+    0.17056907152237813,
+    0.17317940049398606,
+    0.17580101008422658,
+    0.17843392433305644,
+    0.18107816874095856,
+    0.1837337702606475,
+    0.1864007572901454,
+    0.18907915966718522,
+    0.19176900866490132,
+    0.1944703369887722,
+    0.19718317877478245,
+    0.1999075695887756,
+    0.20264354642697202,
+    0.2053911477176291,
+    0.2081504133238238,
+    0.21092138454734083,
+    0.21370410413365107,
+    0.21649861627796915,
+    0.2193049666323795,
+    0.22212320231402402,
+    0.22495337191434595,
+    0.2277955255093866,
+    0.23064971467113443,
+    0.2335159924799272,
+    0.23639441353790985,
+    0.23928503398355414,
+    0.24218791150724578,
+    0.24510310536794908,
+    0.24803067641095897,
+    0.2509706870867539,
+    0.2539232014709637,
+    0.25688828528546964,
+    0.2598660059206545,
+    0.262856432458824,
+    0.2658596356988219,
+    0.2688756881818628,
+    0.2719046642186109,
+    0.2749466399175319,
+    0.2780016932145501,
+    0.28106990390404474,
+    0.2841513536712195,
+    0.28724612612588557,
+    0.2903543068376969,
+    0.2934759833728831,
+    0.29661124533252414,
+    0.29976018439241736,
+    0.30292289434458747,
+    0.3060994711404956,
+    0.309290012936005,
+    0.3124946201381649,
+    0.3157133954538791,
+    0.31894644394052635,
+    0.3221938730586072,
+    0.3254557927264936,
+    0.32873231537736297,
+    0.33202355601840294,
+    0.33532963229237805,
+    0.33865066454165416,
+    0.3419867758747828,
+    0.3453380922357528,
+    0.3487047424760228,
+    0.352086858429455,
+    0.3554845749902772,
+    0.3588980301942076,
+    0.36232736530288456,
+    0.3657727248917522,
+    0.3692342569415612,
+    0.3727121129336531,
+    0.37620644794920827,
+    0.37971742077264614,
+    0.38324519399937895,
+    0.3867899341481332,
+    0.39035181177806394,
+    0.3939310016109036,
+    0.39752768265839955,
+    0.40114203835531276,
+    0.4047742566982659,
+    0.4084245303907476,
+    0.4120930569946006,
+    0.4157800390883405,
+    0.41948568443267875,
+    0.42321020614364263,
+    0.4269538228737166,
+    0.43071675900145545,
+    0.4344992448300502,
+    0.4383015167953631,
+    0.44212381768398124,
+    0.4459663968618805,
+    0.44982951051432996,
+    0.45371342189771635,
+    0.4576184016040143,
+    0.46154472783868417,
+    0.46549268671283583,
+    0.46946257255056234,
+    0.4734546882124133,
+    0.47746934543605646,
+    0.4815068651952555,
+    0.4855675780783824,
+    0.4896518246877808,
+    0.49375995606140355,
+    0.4978923341182651,
+    0.5020493321293772,
+    0.5062313352159789,
+    0.5104387408770255,
+    0.5146719595480733,
+    0.5189314151938842,
+    0.5232175459372805,
+    0.527530804727015,
+    0.5318716600476661,
+    0.5362405966748599,
+    0.5406381164794234,
+    0.5450647392844247,
+    0.5495210037794434,
+    0.5540074684968394,
+    0.5585247128552735,
+    0.5630733382762669,
+    0.5676539693801924,
+    0.572267255268761,
+    0.5769138709018312,
+    0.5815945185772232,
+    0.5863099295231834,
+    0.5910608656142436,
+    0.5958481212224515,
+    0.60067252521736,
+    0.6055349431297681,
+    0.6104362794960252,
+    0.6153774804018168,
+    0.6203595362467365,
+    0.6253834847537152,
+    0.6304504142505621,
+    0.6355614672545433,
+    0.6407178443952004,
+    0.6459208087155669,
+    0.6511716903977389,
+    0.6564718919655339,
+    0.6618228940249433,
+    0.6672262616124814,
+    0.6726836512326537,
+    0.6781968186789901,
+    0.6837676277488615,
+    0.6893980599812071,
+    0.6950902255690677,
+    0.7008463756263694,
+    0.7066689160218997,
+    0.712560423034389,
+    0.7185236611329685,
+    0.7245616032495925,
+    0.7306774539875782,
+    0.7368746763076415,
+    0.7431570223555447,
+    0.7495285692516105,
+    0.7559937608626146,
+    0.7625574568356785,
+    0.7692249905122004,
+    0.7760022377863586,
+    0.7828956995682811,
+    0.7899126013157988,
+    0.7970610141976339,
+    0.8043500039744211,
+    0.8117898158287506,
+    0.8193921064459905,
+    0.8271702391260124,
+    0.8351396643736287,
+    0.84331841857414,
+    0.8517277892436546,
+    0.8603932209173373,
+    0.8693455783190807,
+    0.8786229571533128,
+    0.8882733663206875,
+    0.8983588603753003,
+    0.9089622209194793,
+    0.920198433560893,
+    0.9322360120041384,
+    0.9453410543111422,
+    0.9599832760747625,
+    0.9771612575982122
+];
+
+/// Draws a standard normal (mean `0`, standard deviation `1`) sample using the Ziggurat method.
+pub(crate) fn standard_normal(random: &mut Random) -> f64 {
+    loop {
+        let bits = random.next_u32();
+        let i = (bits & 0xFF) as usize;
+        let negative_tail = bits & 0x100 != 0;
+
+        let u = random.next_f32() as f64 * 2. - 1.;
+        let x_cand = u * X[i];
+
+        if x_cand.abs() < X[i + 1] {
+            return x_cand;
+        }
+
+        if i == 0 {
+            if let Some(tail) = sample_tail(random, negative_tail) {
+                return tail;
+            }
+
+            continue;
+        }
+
+        let u_y = random.next_f32() as f64;
+        let threshold = Y[i] + u_y * (Y[i - 1] - Y[i]);
+
+        if threshold < (-0.5 * x_cand * x_cand).exp() {
+            return x_cand;
+        }
+    }
+}
+
+/// Samples from the tail of the distribution (beyond `X[0]`), returning `None` on rejection
+/// so the caller can retry with a freshly drawn layer.
+fn sample_tail(random: &mut Random, negative: bool) -> Option<f64> {
+    let u1 = random.next_f32() as f64;
+    let u2 = random.next_f32() as f64;
+
+    if u1 <= 0. || u2 <= 0. {
+        return None;
+    }
+
+    let tail = X[0] - u1.ln() / X[0];
+
+    if -2. * u2.ln() > tail * tail {
+        Some(if negative { -tail } else { tail })
+    } else {
+        None
+    }
+}
@@ -0,0 +1,37 @@
+use unity_random::Random;
+
+#[test]
+fn exponential_is_non_negative() {
+    let mut random = Random::new();
+    random.init_state(719188662);
+
+    for _ in 0..1000 {
+        assert!(random.exponential(2.5) >= 0.);
+    }
+}
+
+#[test]
+fn poisson_mean_is_close_to_lambda_for_small_lambda() {
+    let mut random = Random::new();
+    random.init_state(0);
+
+    let lambda = 4.;
+    let samples = 10_000;
+    let sum: u32 = (0..samples).map(|_| random.poisson(lambda)).sum();
+    let mean = sum as f32 / samples as f32;
+
+    assert!((mean - lambda).abs() < 0.2);
+}
+
+#[test]
+fn poisson_mean_is_close_to_lambda_for_large_lambda() {
+    let mut random = Random::new();
+    random.init_state(1);
+
+    let lambda = 50.;
+    let samples = 10_000;
+    let sum: u32 = (0..samples).map(|_| random.poisson(lambda)).sum();
+    let mean = sum as f32 / samples as f32;
+
+    assert!((mean - lambda).abs() < 2.);
+}
@@ -0,0 +1,50 @@
+use unity_random::Random;
+
+#[test]
+fn gaussian_mean_and_variance_converge_for_standard_normal() {
+    let mut random = Random::new();
+    random.init_state(2456539);
+
+    let samples = 100_000;
+    let values: Vec<f32> = (0..samples).map(|_| random.next_gaussian(0., 1.)).collect();
+
+    let mean: f32 = values.iter().sum::<f32>() / samples as f32;
+    let variance: f32 =
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / samples as f32;
+
+    assert!(mean.abs() < 0.02);
+    assert!((variance - 1.).abs() < 0.05);
+}
+
+#[test]
+fn gaussian_mean_and_variance_converge_for_shifted_distribution() {
+    let mut random = Random::new();
+    random.init_state(998244353);
+
+    let mean_param = 10.;
+    let std_dev_param = 3.;
+    let samples = 100_000;
+    let values: Vec<f32> = (0..samples)
+        .map(|_| random.next_gaussian(mean_param, std_dev_param))
+        .collect();
+
+    let mean: f32 = values.iter().sum::<f32>() / samples as f32;
+    let variance: f32 =
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / samples as f32;
+
+    assert!((mean - mean_param).abs() < 0.1);
+    assert!((variance - std_dev_param * std_dev_param).abs() < 0.5);
+}
+
+#[test]
+fn gaussian_mass_near_zero_is_small() {
+    let mut random = Random::new();
+    random.init_state(1000000007);
+
+    let samples = 200_000;
+    let near_zero = (0..samples)
+        .filter(|_| random.next_gaussian(0., 1.).abs() < 1e-6)
+        .count();
+
+    assert!(near_zero < 10);
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "rand_core")]
+
+use rand_core_crate::{RngCore, SeedableRng};
+use unity_random::Random;
+
+#[test]
+fn random_is_usable_as_a_rand_core_rng() {
+    let mut random = Random::seed_from_u64(42);
+
+    let _: u32 = random.next_u32();
+    let _: u64 = random.next_u64();
+}
+
+#[test]
+fn from_seed_is_deterministic() {
+    let mut a = Random::from_seed(7u32.to_le_bytes());
+    let mut b = Random::from_seed(7u32.to_le_bytes());
+
+    for _ in 0..100 {
+        assert_eq!(a.next_u32(), b.next_u32());
+    }
+}
+
+#[test]
+fn fill_bytes_fills_the_entire_buffer() {
+    let mut random = Random::seed_from_u64(1234);
+    let mut twin = Random::seed_from_u64(1234);
+    let mut first = [0u8; 17];
+    let mut second = [0u8; 17];
+
+    random.fill_bytes(&mut first);
+    random.fill_bytes(&mut second);
+
+    assert_ne!(first, second);
+
+    let expected: Vec<u8> = (0..5).flat_map(|_| twin.next_u32().to_le_bytes()).collect();
+    assert_eq!(&first[..], &expected[..17]);
+}
@@ -0,0 +1,44 @@
+use unity_random::Random;
+
+#[test]
+fn range_float_high_precision_stays_in_bounds() {
+    let mut random = Random::new();
+    random.init_state(30029247);
+
+    for _ in 0..1000 {
+        let value = random.range_float_high_precision(0., 1.);
+
+        assert!(value >= 0.);
+        assert!(value < 1.);
+    }
+}
+
+#[test]
+fn range_float_high_precision_mean_and_spread_converge() {
+    let mut random = Random::new();
+    random.init_state(554277);
+
+    let samples = 100_000;
+    let values: Vec<f32> = (0..samples)
+        .map(|_| random.range_float_high_precision(0., 1.))
+        .collect();
+
+    let mean: f32 = values.iter().sum::<f32>() / samples as f32;
+    let max = values.iter().cloned().fold(0f32, f32::max);
+
+    assert!((mean - 0.5).abs() < 0.01);
+    assert!(max > 0.9);
+}
+
+#[test]
+fn range_float_high_precision_resolves_narrow_ranges() {
+    let mut random = Random::new();
+    random.init_state(1);
+
+    let low = 0.1_f32;
+    let high = f32::from_bits(low.to_bits() + 1);
+
+    let value = random.range_float_high_precision(low, high);
+
+    assert_eq!(value, low);
+}
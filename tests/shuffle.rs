@@ -0,0 +1,43 @@
+use unity_random::Random;
+
+#[test]
+fn shuffle_preserves_elements() {
+    let mut random = Random::new();
+    random.init_state(358118);
+
+    let mut values = [0, 1, 2, 3, 4, 5, 6, 7];
+    random.shuffle(&mut values);
+
+    let mut sorted = values;
+    sorted.sort();
+
+    assert_eq!(sorted, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn choose_multiple_returns_distinct_elements_without_replacement() {
+    let mut random = Random::new();
+    random.init_state(30029247);
+
+    let values = [10, 20, 30, 40, 50];
+    let chosen = random.choose_multiple(&values, 3);
+
+    assert_eq!(chosen.len(), 3);
+
+    for (i, a) in chosen.iter().enumerate() {
+        for b in &chosen[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+#[test]
+fn choose_multiple_clamps_amount_to_slice_len() {
+    let mut random = Random::new();
+    random.init_state(1);
+
+    let values = [1, 2, 3];
+    let chosen = random.choose_multiple(&values, 10);
+
+    assert_eq!(chosen.len(), 3);
+}
@@ -0,0 +1,59 @@
+use unity_random::{Random, WeightedIndex, WeightedIndexError};
+
+#[test]
+fn weighted_index_samples_are_in_bounds() {
+    let mut random = Random::new();
+    random.init_state(358118);
+
+    let weights = [1., 0., 5., 2.];
+
+    for _ in 0..100 {
+        let index = random.weighted_index(&weights);
+
+        assert!(index < weights.len());
+        assert_ne!(index, 1);
+    }
+}
+
+#[test]
+fn weighted_index_frequencies_converge_to_weights() {
+    let mut random = Random::new();
+    random.init_state(848124);
+
+    let weights = [1., 1., 2.];
+    let index = WeightedIndex::new(&weights).expect("valid weights");
+    let samples = 100_000;
+
+    let mut counts = [0u32; 3];
+    for _ in 0..samples {
+        counts[index.sample(&mut random)] += 1;
+    }
+
+    let total: f32 = weights.iter().sum();
+    for (count, &weight) in counts.iter().zip(weights.iter()) {
+        let observed = *count as f32 / samples as f32;
+        let expected = weight / total;
+
+        assert!((observed - expected).abs() < 0.01);
+    }
+}
+
+#[test]
+fn weighted_index_rejects_negative_weights() {
+    let err = match WeightedIndex::new(&[1., -1.]) {
+        Err(err) => err,
+        Ok(_) => panic!("expected an error"),
+    };
+
+    assert_eq!(err, WeightedIndexError::NegativeWeight);
+}
+
+#[test]
+fn weighted_index_rejects_all_zero_weights() {
+    let err = match WeightedIndex::new(&[0., 0., 0.]) {
+        Err(err) => err,
+        Ok(_) => panic!("expected an error"),
+    };
+
+    assert_eq!(err, WeightedIndexError::AllZero);
+}